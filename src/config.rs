@@ -0,0 +1,281 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A parsed piece of a format template: either literal text to copy through
+/// verbatim, or a `$name` placeholder to be replaced with a computed value.
+#[derive(Debug, PartialEq)]
+pub enum Token {
+    Literal(String),
+    Variable(String),
+}
+
+#[derive(Deserialize)]
+pub struct Symbols {
+    #[serde(default = "default_model_symbol")]
+    pub model: String,
+    #[serde(default = "default_dir_symbol")]
+    pub dir: String,
+    #[serde(default = "default_git_branch_symbol")]
+    pub git_branch: String,
+    #[serde(default = "default_git_state_symbol")]
+    pub git_state: String,
+    #[serde(default = "default_tokens_symbol")]
+    pub tokens: String,
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Symbols {
+            model: default_model_symbol(),
+            dir: default_dir_symbol(),
+            git_branch: default_git_branch_symbol(),
+            git_state: default_git_state_symbol(),
+            tokens: default_tokens_symbol(),
+        }
+    }
+}
+
+fn default_model_symbol() -> String {
+    "\u{1F916}".to_string()
+}
+
+fn default_dir_symbol() -> String {
+    "\u{1F4C1}".to_string()
+}
+
+fn default_git_branch_symbol() -> String {
+    "\u{1F33F}".to_string()
+}
+
+fn default_git_state_symbol() -> String {
+    "\u{2699}".to_string()
+}
+
+fn default_tokens_symbol() -> String {
+    "\u{1FA99}".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct Thresholds {
+    #[serde(default = "default_warn")]
+    pub warn: u32,
+    #[serde(default = "default_critical")]
+    pub critical: u32,
+    #[serde(default = "default_auto_compact_factor")]
+    pub auto_compact_factor: f64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds {
+            warn: default_warn(),
+            critical: default_critical(),
+            auto_compact_factor: default_auto_compact_factor(),
+        }
+    }
+}
+
+fn default_warn() -> u32 {
+    70
+}
+
+fn default_critical() -> u32 {
+    90
+}
+
+fn default_auto_compact_factor() -> f64 {
+    0.8
+}
+
+/// ANSI/truecolor escape codes applied to the percentage segment (and
+/// reused for the clean-status checkmark, which shares the "ok" semantics).
+#[derive(Deserialize)]
+pub struct Styles {
+    #[serde(default = "default_ok_style")]
+    pub ok: String,
+    #[serde(default = "default_warn_style")]
+    pub warn: String,
+    #[serde(default = "default_critical_style")]
+    pub critical: String,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Styles {
+            ok: default_ok_style(),
+            warn: default_warn_style(),
+            critical: default_critical_style(),
+        }
+    }
+}
+
+fn default_ok_style() -> String {
+    "\x1b[32m".to_string()
+}
+
+fn default_warn_style() -> String {
+    "\x1b[33m".to_string()
+}
+
+fn default_critical_style() -> String {
+    "\x1b[31m".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct Directory {
+    #[serde(default = "default_truncation_length")]
+    pub truncation_length: usize,
+}
+
+impl Default for Directory {
+    fn default() -> Self {
+        Directory {
+            truncation_length: default_truncation_length(),
+        }
+    }
+}
+
+fn default_truncation_length() -> usize {
+    3
+}
+
+#[derive(Deserialize, Default)]
+pub struct Config {
+    pub format: Option<String>,
+    #[serde(default)]
+    pub symbols: Symbols,
+    #[serde(default)]
+    pub thresholds: Thresholds,
+    #[serde(default)]
+    pub styles: Styles,
+    #[serde(default)]
+    pub directory: Directory,
+}
+
+impl Config {
+    /// The format template to render, falling back to a default built from
+    /// `symbols` when the user hasn't supplied one explicitly.
+    pub fn format_template(&self) -> String {
+        self.format.clone().unwrap_or_else(|| {
+            format!(
+                "{} $model | {} $dir$git_branch$git_state | {} $tokens | $percent",
+                self.symbols.model, self.symbols.dir, self.symbols.tokens
+            )
+        })
+    }
+}
+
+/// Resolves the config file path: `$CLAUDE_CODE_STATUSLINE_CONFIG` if set,
+/// otherwise `~/.config/claude_code_statusline/config.toml`.
+pub fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("CLAUDE_CODE_STATUSLINE_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let home = env::var("HOME").ok()?;
+    Some(PathBuf::from(home).join(".config/claude_code_statusline/config.toml"))
+}
+
+/// Loads the config file if present and valid, otherwise returns the
+/// built-in default (which reproduces the tool's original hardcoded output).
+pub fn load_config() -> Config {
+    config_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Splits a format string like `"$model | $dir"` into an ordered list of
+/// literal and variable tokens, mirroring Starship's `StringFormatter`.
+pub fn parse_format(format: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = format.chars().peekable();
+    let mut literal = String::new();
+
+    while let Some(c) = chars.next() {
+        if c == '$' {
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+            let mut name = String::new();
+            while let Some(&next) = chars.peek() {
+                if next.is_alphanumeric() || next == '_' {
+                    name.push(next);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Variable(name));
+        } else {
+            literal.push(c);
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+
+    tokens
+}
+
+/// Renders parsed tokens against a set of computed `$name -> value` pairs.
+/// Unknown variables render as an empty string.
+pub fn render(tokens: &[Token], values: &std::collections::HashMap<&str, String>) -> String {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Literal(s) => s.clone(),
+            Token::Variable(name) => values.get(name.as_str()).cloned().unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_format_literal_and_variables() {
+        let tokens = parse_format("$model | $dir");
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Variable("model".to_string()),
+                Token::Literal(" | ".to_string()),
+                Token::Variable("dir".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_format_no_variables() {
+        let tokens = parse_format("just text");
+        assert_eq!(tokens, vec![Token::Literal("just text".to_string())]);
+    }
+
+    #[test]
+    fn test_render_substitutes_known_and_blanks_unknown() {
+        let tokens = parse_format("$model-$missing");
+        let mut values = std::collections::HashMap::new();
+        values.insert("model", "Opus".to_string());
+        assert_eq!(render(&tokens, &values), "Opus-");
+    }
+
+    #[test]
+    fn test_config_default_matches_original_layout() {
+        let config = Config::default();
+        assert_eq!(
+            config.format_template(),
+            "\u{1F916} $model | \u{1F4C1} $dir$git_branch$git_state | \u{1FA99} $tokens | $percent"
+        );
+        assert_eq!(config.thresholds.warn, 70);
+        assert_eq!(config.thresholds.critical, 90);
+        assert_eq!(config.thresholds.auto_compact_factor, 0.8);
+        assert_eq!(config.styles.ok, "\x1b[32m");
+        assert_eq!(config.styles.warn, "\x1b[33m");
+        assert_eq!(config.styles.critical, "\x1b[31m");
+        assert_eq!(config.directory.truncation_length, 3);
+        assert_eq!(config.symbols.git_state, "\u{2699}");
+    }
+}
@@ -1,6 +1,10 @@
+mod config;
+
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
 use std::io::{self, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Deserialize, Default)]
@@ -34,43 +38,318 @@ struct StatusData {
     context_window: Option<ContextWindow>,
 }
 
-fn get_git_branch(dir: &str) -> Option<String> {
-    // Try symbolic-ref first (works even without commits)
+trait GitProvider {
+    fn status(&self, dir: &str) -> Option<GitStatus>;
+    fn state(&self, dir: &str) -> Option<GitState>;
+}
+
+struct CommandGitProvider;
+
+impl GitProvider for CommandGitProvider {
+    fn status(&self, dir: &str) -> Option<GitStatus> {
+        get_git_status(dir)
+    }
+
+    fn state(&self, dir: &str) -> Option<GitState> {
+        get_git_state(dir)
+    }
+}
+
+const REPO_ROOT_MARKER: &str = "\u{1F4CC}";
+const TRUNCATION_SYMBOL: &str = "\u{2026}/";
+
+fn get_repo_root(dir: &str) -> Option<String> {
     let output = Command::new("git")
-        .args(["symbolic-ref", "--short", "HEAD"])
+        .args(["rev-parse", "--show-toplevel"])
         .current_dir(dir)
         .stderr(std::process::Stdio::null())
         .output()
         .ok()?;
 
     if output.status.success() {
-        let branch = String::from_utf8_lossy(&output.stdout)
-            .trim()
+        let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !root.is_empty() {
+            return Some(root);
+        }
+    }
+    None
+}
+
+fn truncate_components(components: &[&str], limit: usize) -> String {
+    if limit > 0 && components.len() > limit {
+        let tail = &components[components.len() - limit..];
+        format!("{}{}", TRUNCATION_SYMBOL, tail.join("/"))
+    } else {
+        components.join("/")
+    }
+}
+
+fn format_directory(dir_path: &str, limit: usize) -> String {
+    let path = Path::new(dir_path);
+
+    let Some(root) = get_repo_root(dir_path) else {
+        return path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(".")
             .to_string();
-        if !branch.is_empty() {
-            return Some(branch);
+    };
+
+    let root_path = Path::new(&root);
+    if path == root_path {
+        let repo_name = root_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(".");
+        return format!("{} {}", REPO_ROOT_MARKER, repo_name);
+    }
+
+    let components: Vec<&str> = path
+        .strip_prefix(root_path)
+        .unwrap_or(path)
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+
+    truncate_components(&components, limit)
+}
+
+// Modeled on Starship's `git_state` module.
+#[derive(Debug, Clone, PartialEq)]
+enum GitState {
+    Rebasing { step: Option<u32>, total: Option<u32> },
+    Merging,
+    CherryPicking,
+    Reverting,
+    Bisecting,
+}
+
+impl GitState {
+    fn label(&self) -> String {
+        match self {
+            GitState::Rebasing {
+                step: Some(step),
+                total: Some(total),
+            } => format!("REBASING {}/{}", step, total),
+            GitState::Rebasing { .. } => "REBASING".to_string(),
+            GitState::Merging => "MERGING".to_string(),
+            GitState::CherryPicking => "CHERRY-PICKING".to_string(),
+            GitState::Reverting => "REVERTING".to_string(),
+            GitState::Bisecting => "BISECTING".to_string(),
         }
     }
+}
 
-    // Fallback to rev-parse (for detached HEAD)
+fn get_git_dir(dir: &str) -> Option<PathBuf> {
     let output = Command::new("git")
-        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .args(["rev-parse", "--git-dir"])
         .current_dir(dir)
         .stderr(std::process::Stdio::null())
         .output()
         .ok()?;
 
-    if output.status.success() {
-        let branch = String::from_utf8_lossy(&output.stdout)
-            .trim()
-            .to_string();
-        if !branch.is_empty() {
-            return Some(branch);
-        }
+    if !output.status.success() {
+        return None;
+    }
+
+    let git_dir = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if git_dir.is_empty() {
+        return None;
+    }
+
+    Some(Path::new(dir).join(git_dir))
+}
+
+fn read_progress(state_dir: &Path, numerator_file: &str, denominator_file: &str) -> (Option<u32>, Option<u32>) {
+    let step = fs::read_to_string(state_dir.join(numerator_file))
+        .ok()
+        .and_then(|s| s.trim().parse().ok());
+    let total = fs::read_to_string(state_dir.join(denominator_file))
+        .ok()
+        .and_then(|s| s.trim().parse().ok());
+    (step, total)
+}
+
+fn detect_git_state(git_dir: &Path) -> Option<GitState> {
+    let rebase_merge = git_dir.join("rebase-merge");
+    let rebase_apply = git_dir.join("rebase-apply");
+    if rebase_merge.is_dir() {
+        let (step, total) = read_progress(&rebase_merge, "msgnum", "end");
+        return Some(GitState::Rebasing { step, total });
+    }
+    if rebase_apply.is_dir() {
+        let (step, total) = read_progress(&rebase_apply, "next", "last");
+        return Some(GitState::Rebasing { step, total });
+    }
+    if git_dir.join("MERGE_HEAD").is_file() {
+        return Some(GitState::Merging);
+    }
+    if git_dir.join("CHERRY_PICK_HEAD").is_file() {
+        return Some(GitState::CherryPicking);
+    }
+    if git_dir.join("REVERT_HEAD").is_file() {
+        return Some(GitState::Reverting);
+    }
+    if git_dir.join("BISECT_LOG").is_file() {
+        return Some(GitState::Bisecting);
     }
     None
 }
 
+fn get_git_state(dir: &str) -> Option<GitState> {
+    let git_dir = get_git_dir(dir)?;
+    detect_git_state(&git_dir)
+}
+
+#[derive(Default, Clone)]
+struct GitStatus {
+    branch: Option<String>,
+    untracked: u32,
+    modified: u32,
+    deleted: u32,
+    staged: u32,
+    renamed: u32,
+    conflicted: u32,
+    stashed: u32,
+    ahead: u32,
+    behind: u32,
+}
+
+impl GitStatus {
+    fn is_clean(&self) -> bool {
+        self.untracked == 0
+            && self.modified == 0
+            && self.deleted == 0
+            && self.staged == 0
+            && self.renamed == 0
+            && self.conflicted == 0
+            && self.stashed == 0
+    }
+
+    fn render(&self, ok_style: &str) -> String {
+        let mut out = String::new();
+
+        if self.ahead > 0 && self.behind > 0 {
+            out.push('\u{21d5}');
+        } else if self.ahead > 0 {
+            out.push_str(&format!("\u{21e1}{}", self.ahead));
+        } else if self.behind > 0 {
+            out.push_str(&format!("\u{21e3}{}", self.behind));
+        }
+
+        if self.is_clean() {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            out.push_str(&format!("{}\u{2714}\x1b[0m", ok_style));
+            return out;
+        }
+
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        if self.conflicted > 0 {
+            out.push('=');
+        }
+        if self.staged > 0 {
+            out.push('+');
+        }
+        if self.modified > 0 {
+            out.push('!');
+        }
+        if self.deleted > 0 {
+            out.push('\u{2718}');
+        }
+        if self.renamed > 0 {
+            out.push('\u{00bb}');
+        }
+        if self.untracked > 0 {
+            out.push('?');
+        }
+        if self.stashed > 0 {
+            out.push('$');
+        }
+        out
+    }
+}
+
+fn parse_porcelain_entry(code: &str, status: &mut GitStatus) {
+    let mut chars = code.chars();
+    let index = chars.next().unwrap_or('.');
+    let worktree = chars.next().unwrap_or('.');
+
+    if index != '.' {
+        status.staged += 1;
+    }
+    match worktree {
+        'M' => status.modified += 1,
+        'D' => status.deleted += 1,
+        _ => {}
+    }
+}
+
+fn count_stashes(dir: &str) -> u32 {
+    Command::new("git")
+        .args(["stash", "list"])
+        .current_dir(dir)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).lines().count() as u32)
+        .unwrap_or(0)
+}
+
+fn get_git_status(dir: &str) -> Option<GitStatus> {
+    let output = Command::new("git")
+        .args(["status", "--porcelain=v2", "--branch"])
+        .current_dir(dir)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut status = GitStatus::default();
+
+    for line in stdout.lines() {
+        if let Some(rest) = line.strip_prefix("# branch.head ") {
+            let head = rest.trim();
+            if !head.is_empty() && head != "(detached)" {
+                status.branch = Some(head.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("# branch.ab ") {
+            let mut parts = rest.split_whitespace();
+            status.ahead = parts
+                .next()
+                .and_then(|s| s.strip_prefix('+'))
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+            status.behind = parts
+                .next()
+                .and_then(|s| s.strip_prefix('-'))
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+        } else if let Some(rest) = line.strip_prefix("1 ") {
+            parse_porcelain_entry(rest, &mut status);
+        } else if let Some(rest) = line.strip_prefix("2 ") {
+            parse_porcelain_entry(rest, &mut status);
+            status.renamed += 1;
+        } else if line.starts_with("u ") {
+            status.conflicted += 1;
+        } else if line.starts_with("? ") {
+            status.untracked += 1;
+        }
+    }
+
+    status.stashed = count_stashes(dir);
+
+    Some(status)
+}
+
 fn format_token_count(tokens: u64) -> String {
     if tokens >= 1_000_000 {
         format!("{:.1}M", tokens as f64 / 1_000_000.0)
@@ -81,7 +360,7 @@ fn format_token_count(tokens: u64) -> String {
     }
 }
 
-fn build_status_line(input: &str) -> Result<String, serde_json::Error> {
+fn build_status_line(input: &str, git: &dyn GitProvider) -> Result<String, serde_json::Error> {
     let data: StatusData = serde_json::from_str(input)?;
 
     let model = data
@@ -95,21 +374,34 @@ fn build_status_line(input: &str) -> Result<String, serde_json::Error> {
         .or(data.cwd)
         .unwrap_or_else(|| ".".to_string());
 
-    let current_dir = Path::new(&current_dir_path)
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or(".")
-        .to_string();
+    let config = config::load_config();
 
-    let git_branch = get_git_branch(&current_dir_path)
-        .map(|b| format!(" | \u{1F33F} {}", b))
+    let current_dir = format_directory(&current_dir_path, config.directory.truncation_length);
+
+    let current_status = git.status(&current_dir_path);
+
+    let git_branch = current_status
+        .as_ref()
+        .and_then(|s| s.branch.clone())
+        .map(|b| {
+            let rendered = current_status
+                .as_ref()
+                .map(|s| s.render(&config.styles.ok))
+                .unwrap_or_default();
+            format!(" | {} {} {}", config.symbols.git_branch, b, rendered)
+        })
+        .unwrap_or_default();
+
+    let git_state = git
+        .state(&current_dir_path)
+        .map(|s| format!(" | {} {}", config.symbols.git_state, s.label()))
         .unwrap_or_default();
 
     let context_window = data.context_window.unwrap_or_default();
     let context_size = context_window.context_window_size.unwrap_or(0);
     let current_usage = context_window.current_usage.unwrap_or_default();
 
-    let auto_compact_limit = (context_size as f64 * 0.8) as u64;
+    let auto_compact_limit = (context_size as f64 * config.thresholds.auto_compact_factor) as u64;
 
     let current_tokens = current_usage.input_tokens.unwrap_or(0)
         + current_usage.cache_creation_input_tokens.unwrap_or(0)
@@ -123,18 +415,27 @@ fn build_status_line(input: &str) -> Result<String, serde_json::Error> {
 
     let token_display = format_token_count(current_tokens);
 
-    let percentage_color = if percentage >= 90 {
-        "\x1b[31m" // Red
-    } else if percentage >= 70 {
-        "\x1b[33m" // Yellow
+    let percentage_color = if percentage >= config.thresholds.critical {
+        &config.styles.critical
+    } else if percentage >= config.thresholds.warn {
+        &config.styles.warn
     } else {
-        "\x1b[32m" // Green
+        &config.styles.ok
     };
 
-    Ok(format!(
-        "\u{1F916} {} | \u{1F4C1} {}{} | \u{1FA99} {} | {}{}%\x1b[0m",
-        model, current_dir, git_branch, token_display, percentage_color, percentage
-    ))
+    let mut values = HashMap::new();
+    values.insert("model", model);
+    values.insert("dir", current_dir);
+    values.insert("git_branch", git_branch);
+    values.insert("git_state", git_state);
+    values.insert("tokens", token_display);
+    values.insert(
+        "percent",
+        format!("{}{}%\x1b[0m", percentage_color, percentage),
+    );
+
+    let tokens = config::parse_format(&config.format_template());
+    Ok(config::render(&tokens, &values))
 }
 
 fn main() {
@@ -144,7 +445,7 @@ fn main() {
         std::process::exit(1);
     }
 
-    match build_status_line(&input) {
+    match build_status_line(&input, &CommandGitProvider) {
         Ok(status_line) => println!("{}", status_line),
         Err(e) => {
             eprintln!("Error parsing JSON: {}", e);
@@ -157,6 +458,22 @@ fn main() {
 mod tests {
     use super::*;
 
+    #[derive(Default)]
+    struct MockGitProvider {
+        status: Option<GitStatus>,
+        state: Option<GitState>,
+    }
+
+    impl GitProvider for MockGitProvider {
+        fn status(&self, _dir: &str) -> Option<GitStatus> {
+            self.status.clone()
+        }
+
+        fn state(&self, _dir: &str) -> Option<GitState> {
+            self.state.clone()
+        }
+    }
+
     #[test]
     fn test_format_token_count_small() {
         assert_eq!(format_token_count(0), "0");
@@ -194,7 +511,7 @@ mod tests {
             }
         }"#;
 
-        let result = build_status_line(input).unwrap();
+        let result = build_status_line(input, &CommandGitProvider).unwrap();
         assert!(result.contains("🤖 Claude Opus"));
         assert!(result.contains("📁 tmp"));
         assert!(result.contains("🪙 65.0K"));
@@ -211,7 +528,7 @@ mod tests {
             }
         }"#;
 
-        let result = build_status_line(input).unwrap();
+        let result = build_status_line(input, &CommandGitProvider).unwrap();
         assert!(result.contains("🤖 Unknown"));
     }
 
@@ -227,7 +544,7 @@ mod tests {
             }
         }"#;
 
-        let result = build_status_line(input).unwrap();
+        let result = build_status_line(input, &CommandGitProvider).unwrap();
         assert!(result.contains("📁 project"));
     }
 
@@ -241,7 +558,7 @@ mod tests {
                 "current_usage": {"input_tokens": 10000}
             }
         }"#;
-        let result = build_status_line(input_green).unwrap();
+        let result = build_status_line(input_green, &CommandGitProvider).unwrap();
         assert!(result.contains("\x1b[32m")); // Green
 
         // Yellow (70-89%)
@@ -252,7 +569,7 @@ mod tests {
                 "current_usage": {"input_tokens": 60000}
             }
         }"#;
-        let result = build_status_line(input_yellow).unwrap();
+        let result = build_status_line(input_yellow, &CommandGitProvider).unwrap();
         assert!(result.contains("\x1b[33m")); // Yellow
 
         // Red (>= 90%)
@@ -263,14 +580,14 @@ mod tests {
                 "current_usage": {"input_tokens": 75000}
             }
         }"#;
-        let result = build_status_line(input_red).unwrap();
+        let result = build_status_line(input_red, &CommandGitProvider).unwrap();
         assert!(result.contains("\x1b[31m")); // Red
     }
 
     #[test]
     fn test_build_status_line_invalid_json() {
         let input = "not valid json";
-        assert!(build_status_line(input).is_err());
+        assert!(build_status_line(input, &CommandGitProvider).is_err());
     }
 
     #[test]
@@ -280,14 +597,257 @@ mod tests {
             "cwd": "/tmp"
         }"#;
 
-        let result = build_status_line(input).unwrap();
+        let result = build_status_line(input, &CommandGitProvider).unwrap();
         assert!(result.contains("🪙 0"));
         assert!(result.contains("0%"));
     }
 
     #[test]
-    fn test_get_git_branch_non_git_dir() {
-        let result = get_git_branch("/tmp");
+    fn test_build_status_line_with_mocked_clean_branch() {
+        let git = MockGitProvider {
+            status: Some(GitStatus {
+                branch: Some("main".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let input = r#"{
+            "cwd": "/tmp",
+            "context_window": {
+                "context_window_size": 100000,
+                "current_usage": {"input_tokens": 1000}
+            }
+        }"#;
+
+        let result = build_status_line(input, &git).unwrap();
+        assert!(result.contains("🌿 main \x1b[32m\u{2714}\x1b[0m"));
+    }
+
+    #[test]
+    fn test_build_status_line_with_mocked_dirty_status() {
+        let git = MockGitProvider {
+            status: Some(GitStatus {
+                branch: Some("feature".to_string()),
+                modified: 1,
+                staged: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let input = r#"{
+            "cwd": "/tmp",
+            "context_window": {
+                "context_window_size": 100000,
+                "current_usage": {"input_tokens": 1000}
+            }
+        }"#;
+
+        let result = build_status_line(input, &git).unwrap();
+        assert!(result.contains("🌿 feature +!"));
+    }
+
+    #[test]
+    fn test_build_status_line_with_mocked_git_state() {
+        let git = MockGitProvider {
+            status: Some(GitStatus {
+                branch: Some("main".to_string()),
+                ..Default::default()
+            }),
+            state: Some(GitState::Rebasing {
+                step: Some(2),
+                total: Some(5),
+            }),
+        };
+        let input = r#"{
+            "cwd": "/tmp",
+            "context_window": {
+                "context_window_size": 100000,
+                "current_usage": {"input_tokens": 1000}
+            }
+        }"#;
+
+        let result = build_status_line(input, &git).unwrap();
+        assert!(result.contains("\u{2699} REBASING 2/5"));
+    }
+
+    #[test]
+    fn test_build_status_line_with_mocked_no_branch() {
+        let git = MockGitProvider::default();
+        let input = r#"{
+            "cwd": "/tmp",
+            "context_window": {
+                "context_window_size": 100000,
+                "current_usage": {"input_tokens": 1000}
+            }
+        }"#;
+
+        let result = build_status_line(input, &git).unwrap();
+        assert!(!result.contains("🌿"));
+    }
+
+    fn fixture_git_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cc_statusline_fixture_{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_detect_git_state_none() {
+        let git_dir = fixture_git_dir("clean");
+        assert_eq!(detect_git_state(&git_dir), None);
+    }
+
+    #[test]
+    fn test_detect_git_state_rebasing_with_progress() {
+        let git_dir = fixture_git_dir("rebase_merge");
+        let rebase_dir = git_dir.join("rebase-merge");
+        fs::create_dir_all(&rebase_dir).unwrap();
+        fs::write(rebase_dir.join("msgnum"), "2\n").unwrap();
+        fs::write(rebase_dir.join("end"), "5\n").unwrap();
+
+        let state = detect_git_state(&git_dir);
+        assert_eq!(
+            state,
+            Some(GitState::Rebasing {
+                step: Some(2),
+                total: Some(5)
+            })
+        );
+        assert_eq!(state.unwrap().label(), "REBASING 2/5");
+    }
+
+    #[test]
+    fn test_detect_git_state_rebase_apply() {
+        let git_dir = fixture_git_dir("rebase_apply");
+        let rebase_dir = git_dir.join("rebase-apply");
+        fs::create_dir_all(&rebase_dir).unwrap();
+        fs::write(rebase_dir.join("next"), "1\n").unwrap();
+        fs::write(rebase_dir.join("last"), "3\n").unwrap();
+
+        let state = detect_git_state(&git_dir);
+        assert_eq!(
+            state,
+            Some(GitState::Rebasing {
+                step: Some(1),
+                total: Some(3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_detect_git_state_merging() {
+        let git_dir = fixture_git_dir("merging");
+        fs::write(git_dir.join("MERGE_HEAD"), "abc123\n").unwrap();
+        assert_eq!(detect_git_state(&git_dir), Some(GitState::Merging));
+        assert_eq!(GitState::Merging.label(), "MERGING");
+    }
+
+    #[test]
+    fn test_detect_git_state_cherry_picking() {
+        let git_dir = fixture_git_dir("cherry_picking");
+        fs::write(git_dir.join("CHERRY_PICK_HEAD"), "abc123\n").unwrap();
+        assert_eq!(detect_git_state(&git_dir), Some(GitState::CherryPicking));
+    }
+
+    #[test]
+    fn test_detect_git_state_reverting() {
+        let git_dir = fixture_git_dir("reverting");
+        fs::write(git_dir.join("REVERT_HEAD"), "abc123\n").unwrap();
+        assert_eq!(detect_git_state(&git_dir), Some(GitState::Reverting));
+    }
+
+    #[test]
+    fn test_detect_git_state_bisecting() {
+        let git_dir = fixture_git_dir("bisecting");
+        fs::write(git_dir.join("BISECT_LOG"), "git bisect start\n").unwrap();
+        assert_eq!(detect_git_state(&git_dir), Some(GitState::Bisecting));
+    }
+
+    #[test]
+    fn test_get_git_state_non_git_dir() {
+        let result = get_git_state("/tmp");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_get_repo_root_non_git_dir() {
+        let result = get_repo_root("/tmp");
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_format_directory_non_git_dir() {
+        let result = format_directory("/tmp/some/nested/dir", 3);
+        assert_eq!(result, "dir");
+    }
+
+    #[test]
+    fn test_truncate_components_under_limit() {
+        let components = vec!["src", "main.rs"];
+        assert_eq!(truncate_components(&components, 3), "src/main.rs");
+    }
+
+    #[test]
+    fn test_truncate_components_over_limit() {
+        let components = vec!["src", "modules", "foo", "bar"];
+        assert_eq!(
+            truncate_components(&components, 3),
+            "\u{2026}/modules/foo/bar"
+        );
+    }
+
+    #[test]
+    fn test_get_git_status_non_git_dir() {
+        let result = get_git_status("/tmp");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_git_status_clean_render() {
+        let status = GitStatus::default();
+        assert!(status.is_clean());
+        assert_eq!(status.render("\x1b[32m"), "\x1b[32m\u{2714}\x1b[0m");
+    }
+
+    #[test]
+    fn test_git_status_render_symbols() {
+        let status = GitStatus {
+            modified: 1,
+            staged: 1,
+            untracked: 2,
+            ..Default::default()
+        };
+        assert!(!status.is_clean());
+        assert_eq!(status.render("\x1b[32m"), "+!?");
+    }
+
+    #[test]
+    fn test_git_status_render_ahead_behind() {
+        let ahead = GitStatus {
+            ahead: 2,
+            ..Default::default()
+        };
+        assert_eq!(ahead.render("\x1b[32m"), "\u{21e1}2 \x1b[32m\u{2714}\x1b[0m");
+
+        let diverged = GitStatus {
+            ahead: 1,
+            behind: 1,
+            ..Default::default()
+        };
+        assert_eq!(diverged.render("\x1b[32m"), "\u{21d5} \x1b[32m\u{2714}\x1b[0m");
+    }
+
+    #[test]
+    fn test_parse_porcelain_entry() {
+        let mut status = GitStatus::default();
+        parse_porcelain_entry("MM", &mut status);
+        assert_eq!(status.staged, 1);
+        assert_eq!(status.modified, 1);
+
+        let mut status = GitStatus::default();
+        parse_porcelain_entry(".D", &mut status);
+        assert_eq!(status.staged, 0);
+        assert_eq!(status.deleted, 1);
+    }
 }